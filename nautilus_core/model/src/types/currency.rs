@@ -13,7 +13,35 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
 use crate::enums::CurrencyType;
+use crate::types::currency_registry::{ISO4217_CURRENCIES, ISO4217_NUMERIC_TO_ALPHA3};
+
+/// Errors returned when resolving a [`Currency`] from the built-in ISO 4217 registry.
+#[derive(Error, Eq, PartialEq, Clone, Debug)]
+pub enum CurrencyError {
+    #[error("unknown currency code: {0}")]
+    UnknownCode(String),
+    #[error("unknown ISO 4217 numeric code: {0}")]
+    UnknownIsoNumeric(u16),
+}
+
+/// The thousands and decimal separator characters used when rendering an amount in a given
+/// currency's locale, e.g. `(',', '.')` for `$1,234.50` or `('.', ',')` for `1.234,50 €`.
+#[repr(C)]
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde-support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Separators {
+    pub thousands: char,
+    pub decimal: char,
+}
 
 #[repr(C)]
 #[derive(Eq, PartialEq, Clone, Hash, Debug)]
@@ -23,15 +51,22 @@ pub struct Currency {
     pub iso4217: u16,
     pub name: Box<String>,
     pub currency_type: CurrencyType,
+    pub symbol: Box<String>,
+    pub symbol_first: bool,
+    pub separators: Separators,
 }
 
 impl Currency {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         code: &str,
         precision: u8,
         iso4217: u16,
         name: &str,
         currency_type: CurrencyType,
+        symbol: &str,
+        symbol_first: bool,
+        separators: Separators,
     ) -> Currency {
         Currency {
             code: Box::from(code.to_string()),
@@ -39,25 +74,180 @@ impl Currency {
             iso4217,
             name: Box::from(name.to_string()),
             currency_type,
+            symbol: Box::from(symbol.to_string()),
+            symbol_first,
+            separators,
+        }
+    }
+
+    /// Returns the built-in [`Currency`] registered under the given ISO 4217 numeric code.
+    pub fn from_iso_numeric(iso4217: u16) -> Result<Currency, CurrencyError> {
+        let code = ISO4217_NUMERIC_TO_ALPHA3
+            .get(&iso4217)
+            .ok_or(CurrencyError::UnknownIsoNumeric(iso4217))?;
+        Ok(Currency::from_str(code).expect("registry numeric/alpha3 tables are consistent"))
+    }
+
+    /// Renders `value` as a human-readable monetary string in this currency's locale, e.g.
+    /// `$1,234.50` or `1.234,50 €`, honoring [`precision`](Self::precision), [`symbol`](Self::symbol),
+    /// [`symbol_first`](Self::symbol_first) and [`separators`](Self::separators).
+    pub fn format_amount(&self, value: Decimal) -> String {
+        let rounded = value.round_dp(u32::from(self.precision));
+        let sign = if rounded.is_sign_negative() { "-" } else { "" };
+        let raw = format!("{:.*}", self.precision as usize, rounded.abs());
+        let (integer_part, fractional_part) = match raw.split_once('.') {
+            Some((integer, fractional)) => (integer, Some(fractional)),
+            None => (raw.as_str(), None),
+        };
+
+        let grouped_integer = group_thousands(integer_part, self.separators.thousands);
+        let number = match fractional_part {
+            Some(fractional) if !fractional.is_empty() => {
+                format!("{grouped_integer}{}{fractional}", self.separators.decimal)
+            }
+            _ => grouped_integer,
+        };
+
+        if self.symbol_first {
+            format!("{sign}{}{number}", self.symbol)
+        } else {
+            format!("{sign}{number} {}", self.symbol)
+        }
+    }
+}
+
+/// Inserts `separator` every three digits from the right, e.g. `group_thousands("1234", ',')`
+/// returns `"1,234"`.
+fn group_thousands(digits: &str, separator: char) -> String {
+    let chars: Vec<char> = digits.chars().collect();
+    let len = chars.len();
+    let mut result = String::with_capacity(len + len / 3);
+    for (i, c) in chars.iter().enumerate() {
+        if i != 0 && (len - i).is_multiple_of(3) {
+            result.push(separator);
         }
+        result.push(*c);
+    }
+    result
+}
+
+impl FromStr for Currency {
+    type Err = CurrencyError;
+
+    /// Parses a [`Currency`] from its alpha-3 ISO 4217 code (or common crypto ticker), resolving
+    /// precision, name and type from the built-in registry.
+    fn from_str(code: &str) -> Result<Currency, CurrencyError> {
+        let code = code.to_ascii_uppercase();
+        let record = ISO4217_CURRENCIES
+            .get(code.as_str())
+            .ok_or_else(|| CurrencyError::UnknownCode(code.clone()))?;
+        Ok(Currency::new(
+            record.code,
+            record.precision,
+            record.iso4217,
+            record.name,
+            record.currency_type,
+            record.symbol,
+            record.symbol_first,
+            record.separators,
+        ))
     }
 }
 
 #[allow(unused_imports)] // warning: unused import: `std::fmt::Write as FmtWrite`
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal_macros::dec;
+
     use crate::enums::CurrencyType;
-    use crate::types::currency::Currency;
+    use crate::types::currency::{Currency, CurrencyError, Separators};
 
     #[test]
     fn test_price_new() {
-        let currency = Currency::new("AUD", 8, 036, "Australian dollar", CurrencyType::FIAT);
+        let currency = Currency::new(
+            "AUD",
+            8,
+            36,
+            "Australian dollar",
+            CurrencyType::FIAT,
+            "$",
+            true,
+            Separators {
+                thousands: ',',
+                decimal: '.',
+            },
+        );
 
         assert_eq!(currency, currency);
         assert_eq!(currency.code.as_str(), "AUD");
         assert_eq!(currency.precision, 8);
-        assert_eq!(currency.iso4217, 036);
+        assert_eq!(currency.iso4217, 36);
         assert_eq!(currency.name.as_str(), "Australian dollar");
         assert_eq!(currency.currency_type, CurrencyType::FIAT);
+        assert_eq!(currency.symbol.as_str(), "$");
+        assert!(currency.symbol_first);
+    }
+
+    #[test]
+    fn test_from_str_known_fiat() {
+        let currency = Currency::from_str("aud").unwrap();
+
+        assert_eq!(currency.code.as_str(), "AUD");
+        assert_eq!(currency.precision, 2);
+        assert_eq!(currency.iso4217, 36);
+        assert_eq!(currency.currency_type, CurrencyType::FIAT);
+    }
+
+    #[test]
+    fn test_from_str_known_crypto() {
+        let currency = Currency::from_str("BTC").unwrap();
+
+        assert_eq!(currency.code.as_str(), "BTC");
+        assert_eq!(currency.precision, 8);
+        assert_eq!(currency.currency_type, CurrencyType::CRYPTO);
+    }
+
+    #[test]
+    fn test_from_str_unknown_code() {
+        let result = Currency::from_str("XXX");
+
+        assert_eq!(result, Err(CurrencyError::UnknownCode("XXX".to_string())));
+    }
+
+    #[test]
+    fn test_from_iso_numeric_known() {
+        let currency = Currency::from_iso_numeric(840).unwrap();
+
+        assert_eq!(currency.code.as_str(), "USD");
+    }
+
+    #[test]
+    fn test_from_iso_numeric_unknown() {
+        let result = Currency::from_iso_numeric(999);
+
+        assert_eq!(result, Err(CurrencyError::UnknownIsoNumeric(999)));
+    }
+
+    #[test]
+    fn test_format_amount_symbol_first() {
+        let usd = Currency::from_str("USD").unwrap();
+
+        assert_eq!(usd.format_amount(dec!(1234.5)), "$1,234.50");
+    }
+
+    #[test]
+    fn test_format_amount_symbol_after_with_locale_separators() {
+        let eur = Currency::from_str("EUR").unwrap();
+
+        assert_eq!(eur.format_amount(dec!(1234.5)), "1.234,50 €");
+    }
+
+    #[test]
+    fn test_format_amount_negative() {
+        let usd = Currency::from_str("USD").unwrap();
+
+        assert_eq!(usd.format_amount(dec!(-42)), "-$42.00");
     }
 }
\ No newline at end of file
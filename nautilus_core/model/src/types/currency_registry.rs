@@ -0,0 +1,134 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Static ISO 4217 (plus common crypto) currency data, compiled into the binary.
+//!
+//! The tables here back [`Currency::from_str`](crate::types::currency::Currency::from_str) and
+//! [`Currency::from_iso_numeric`](crate::types::currency::Currency::from_iso_numeric), so that
+//! well-known currencies can be looked up by code or numeric ID without the caller needing to
+//! hand-specify precision, name and type.
+
+use crate::enums::CurrencyType;
+use crate::types::currency::Separators;
+
+/// A compile-time record for a well-known currency.
+///
+/// Mirrors the fields of [`Currency`](crate::types::currency::Currency), but uses `&'static str`
+/// so the table can live in a `phf::Map` rather than requiring heap allocation.
+pub struct CurrencyRecord {
+    pub code: &'static str,
+    pub precision: u8,
+    pub iso4217: u16,
+    pub name: &'static str,
+    pub currency_type: CurrencyType,
+    pub symbol: &'static str,
+    pub symbol_first: bool,
+    pub separators: Separators,
+}
+
+const fn seps(thousands: char, decimal: char) -> Separators {
+    Separators { thousands, decimal }
+}
+
+/// Currencies keyed by their ISO 4217 alpha-3 code (or, for crypto, their common ticker).
+pub static ISO4217_CURRENCIES: phf::Map<&'static str, CurrencyRecord> = phf::phf_map! {
+    // Majors
+    "USD" => CurrencyRecord { code: "USD", precision: 2, iso4217: 840, name: "United States dollar", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    "EUR" => CurrencyRecord { code: "EUR", precision: 2, iso4217: 978, name: "Euro", currency_type: CurrencyType::FIAT, symbol: "€", symbol_first: false, separators: seps('.', ',') },
+    "GBP" => CurrencyRecord { code: "GBP", precision: 2, iso4217: 826, name: "Pound sterling", currency_type: CurrencyType::FIAT, symbol: "£", symbol_first: true, separators: seps(',', '.') },
+    "JPY" => CurrencyRecord { code: "JPY", precision: 0, iso4217: 392, name: "Japanese yen", currency_type: CurrencyType::FIAT, symbol: "¥", symbol_first: true, separators: seps(',', '.') },
+    "AUD" => CurrencyRecord { code: "AUD", precision: 2, iso4217: 36, name: "Australian dollar", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    "CAD" => CurrencyRecord { code: "CAD", precision: 2, iso4217: 124, name: "Canadian dollar", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    "CHF" => CurrencyRecord { code: "CHF", precision: 2, iso4217: 756, name: "Swiss franc", currency_type: CurrencyType::FIAT, symbol: "CHF", symbol_first: false, separators: seps('\'', '.') },
+    "NZD" => CurrencyRecord { code: "NZD", precision: 2, iso4217: 554, name: "New Zealand dollar", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    // Other fiat
+    "CNY" => CurrencyRecord { code: "CNY", precision: 2, iso4217: 156, name: "Renminbi", currency_type: CurrencyType::FIAT, symbol: "¥", symbol_first: true, separators: seps(',', '.') },
+    "CNH" => CurrencyRecord { code: "CNH", precision: 2, iso4217: 156, name: "Renminbi (offshore)", currency_type: CurrencyType::FIAT, symbol: "¥", symbol_first: true, separators: seps(',', '.') },
+    "HKD" => CurrencyRecord { code: "HKD", precision: 2, iso4217: 344, name: "Hong Kong dollar", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    "SGD" => CurrencyRecord { code: "SGD", precision: 2, iso4217: 702, name: "Singapore dollar", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    "SEK" => CurrencyRecord { code: "SEK", precision: 2, iso4217: 752, name: "Swedish krona", currency_type: CurrencyType::FIAT, symbol: "kr", symbol_first: false, separators: seps(' ', ',') },
+    "NOK" => CurrencyRecord { code: "NOK", precision: 2, iso4217: 578, name: "Norwegian krone", currency_type: CurrencyType::FIAT, symbol: "kr", symbol_first: false, separators: seps(' ', ',') },
+    "DKK" => CurrencyRecord { code: "DKK", precision: 2, iso4217: 208, name: "Danish krone", currency_type: CurrencyType::FIAT, symbol: "kr", symbol_first: false, separators: seps('.', ',') },
+    "MXN" => CurrencyRecord { code: "MXN", precision: 2, iso4217: 484, name: "Mexican peso", currency_type: CurrencyType::FIAT, symbol: "$", symbol_first: true, separators: seps(',', '.') },
+    "ZAR" => CurrencyRecord { code: "ZAR", precision: 2, iso4217: 710, name: "South African rand", currency_type: CurrencyType::FIAT, symbol: "R", symbol_first: true, separators: seps(',', '.') },
+    "TRY" => CurrencyRecord { code: "TRY", precision: 2, iso4217: 949, name: "Turkish lira", currency_type: CurrencyType::FIAT, symbol: "₺", symbol_first: false, separators: seps('.', ',') },
+    "INR" => CurrencyRecord { code: "INR", precision: 2, iso4217: 356, name: "Indian rupee", currency_type: CurrencyType::FIAT, symbol: "₹", symbol_first: true, separators: seps(',', '.') },
+    "BRL" => CurrencyRecord { code: "BRL", precision: 2, iso4217: 986, name: "Brazilian real", currency_type: CurrencyType::FIAT, symbol: "R$", symbol_first: true, separators: seps('.', ',') },
+    "RUB" => CurrencyRecord { code: "RUB", precision: 2, iso4217: 643, name: "Russian ruble", currency_type: CurrencyType::FIAT, symbol: "₽", symbol_first: false, separators: seps(' ', ',') },
+    "KRW" => CurrencyRecord { code: "KRW", precision: 0, iso4217: 410, name: "South Korean won", currency_type: CurrencyType::FIAT, symbol: "₩", symbol_first: true, separators: seps(',', '.') },
+    "THB" => CurrencyRecord { code: "THB", precision: 2, iso4217: 764, name: "Thai baht", currency_type: CurrencyType::FIAT, symbol: "฿", symbol_first: true, separators: seps(',', '.') },
+    "IDR" => CurrencyRecord { code: "IDR", precision: 2, iso4217: 360, name: "Indonesian rupiah", currency_type: CurrencyType::FIAT, symbol: "Rp", symbol_first: true, separators: seps('.', ',') },
+    "PLN" => CurrencyRecord { code: "PLN", precision: 2, iso4217: 985, name: "Polish zloty", currency_type: CurrencyType::FIAT, symbol: "zł", symbol_first: false, separators: seps(' ', ',') },
+    "CZK" => CurrencyRecord { code: "CZK", precision: 2, iso4217: 203, name: "Czech koruna", currency_type: CurrencyType::FIAT, symbol: "Kč", symbol_first: false, separators: seps(' ', ',') },
+    "HUF" => CurrencyRecord { code: "HUF", precision: 2, iso4217: 348, name: "Hungarian forint", currency_type: CurrencyType::FIAT, symbol: "Ft", symbol_first: false, separators: seps(' ', ',') },
+    "ILS" => CurrencyRecord { code: "ILS", precision: 2, iso4217: 376, name: "Israeli new shekel", currency_type: CurrencyType::FIAT, symbol: "₪", symbol_first: true, separators: seps(',', '.') },
+    "PHP" => CurrencyRecord { code: "PHP", precision: 2, iso4217: 608, name: "Philippine peso", currency_type: CurrencyType::FIAT, symbol: "₱", symbol_first: true, separators: seps(',', '.') },
+    "MYR" => CurrencyRecord { code: "MYR", precision: 2, iso4217: 458, name: "Malaysian ringgit", currency_type: CurrencyType::FIAT, symbol: "RM", symbol_first: true, separators: seps(',', '.') },
+    "TWD" => CurrencyRecord { code: "TWD", precision: 2, iso4217: 901, name: "New Taiwan dollar", currency_type: CurrencyType::FIAT, symbol: "NT$", symbol_first: true, separators: seps(',', '.') },
+    "AED" => CurrencyRecord { code: "AED", precision: 2, iso4217: 784, name: "United Arab Emirates dirham", currency_type: CurrencyType::FIAT, symbol: "د.إ", symbol_first: false, separators: seps(',', '.') },
+    "SAR" => CurrencyRecord { code: "SAR", precision: 2, iso4217: 682, name: "Saudi riyal", currency_type: CurrencyType::FIAT, symbol: "﷼", symbol_first: false, separators: seps(',', '.') },
+    // Crypto
+    "BTC" => CurrencyRecord { code: "BTC", precision: 8, iso4217: 0, name: "Bitcoin", currency_type: CurrencyType::CRYPTO, symbol: "₿", symbol_first: false, separators: seps(',', '.') },
+    "ETH" => CurrencyRecord { code: "ETH", precision: 8, iso4217: 0, name: "Ethereum", currency_type: CurrencyType::CRYPTO, symbol: "Ξ", symbol_first: false, separators: seps(',', '.') },
+    "USDT" => CurrencyRecord { code: "USDT", precision: 6, iso4217: 0, name: "Tether", currency_type: CurrencyType::CRYPTO, symbol: "USDT", symbol_first: false, separators: seps(',', '.') },
+    "USDC" => CurrencyRecord { code: "USDC", precision: 6, iso4217: 0, name: "USD Coin", currency_type: CurrencyType::CRYPTO, symbol: "USDC", symbol_first: false, separators: seps(',', '.') },
+    "XRP" => CurrencyRecord { code: "XRP", precision: 6, iso4217: 0, name: "Ripple", currency_type: CurrencyType::CRYPTO, symbol: "XRP", symbol_first: false, separators: seps(',', '.') },
+    "LTC" => CurrencyRecord { code: "LTC", precision: 8, iso4217: 0, name: "Litecoin", currency_type: CurrencyType::CRYPTO, symbol: "Ł", symbol_first: false, separators: seps(',', '.') },
+    "BCH" => CurrencyRecord { code: "BCH", precision: 8, iso4217: 0, name: "Bitcoin Cash", currency_type: CurrencyType::CRYPTO, symbol: "BCH", symbol_first: false, separators: seps(',', '.') },
+    "BNB" => CurrencyRecord { code: "BNB", precision: 8, iso4217: 0, name: "Binance Coin", currency_type: CurrencyType::CRYPTO, symbol: "BNB", symbol_first: false, separators: seps(',', '.') },
+    "ADA" => CurrencyRecord { code: "ADA", precision: 6, iso4217: 0, name: "Cardano", currency_type: CurrencyType::CRYPTO, symbol: "ADA", symbol_first: false, separators: seps(',', '.') },
+    "DOT" => CurrencyRecord { code: "DOT", precision: 8, iso4217: 0, name: "Polkadot", currency_type: CurrencyType::CRYPTO, symbol: "DOT", symbol_first: false, separators: seps(',', '.') },
+    "SOL" => CurrencyRecord { code: "SOL", precision: 8, iso4217: 0, name: "Solana", currency_type: CurrencyType::CRYPTO, symbol: "SOL", symbol_first: false, separators: seps(',', '.') },
+    "DOGE" => CurrencyRecord { code: "DOGE", precision: 8, iso4217: 0, name: "Dogecoin", currency_type: CurrencyType::CRYPTO, symbol: "Ð", symbol_first: false, separators: seps(',', '.') },
+};
+
+/// Maps an ISO 4217 numeric code to its alpha-3 code, for currencies that have one.
+///
+/// Crypto currencies have no ISO 4217 numeric assignment and so are absent from this table;
+/// look them up directly via [`ISO4217_CURRENCIES`] instead.
+pub static ISO4217_NUMERIC_TO_ALPHA3: phf::Map<u16, &'static str> = phf::phf_map! {
+    840u16 => "USD",
+    978u16 => "EUR",
+    826u16 => "GBP",
+    392u16 => "JPY",
+    36u16 => "AUD",
+    124u16 => "CAD",
+    756u16 => "CHF",
+    554u16 => "NZD",
+    156u16 => "CNY",
+    344u16 => "HKD",
+    702u16 => "SGD",
+    752u16 => "SEK",
+    578u16 => "NOK",
+    208u16 => "DKK",
+    484u16 => "MXN",
+    710u16 => "ZAR",
+    949u16 => "TRY",
+    356u16 => "INR",
+    986u16 => "BRL",
+    643u16 => "RUB",
+    410u16 => "KRW",
+    764u16 => "THB",
+    360u16 => "IDR",
+    985u16 => "PLN",
+    203u16 => "CZK",
+    348u16 => "HUF",
+    376u16 => "ILS",
+    608u16 => "PHP",
+    458u16 => "MYR",
+    901u16 => "TWD",
+    784u16 => "AED",
+    682u16 => "SAR",
+};
@@ -0,0 +1,167 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing for wire/human strings that embed a unit in the value, e.g. `"1 BTC"`, `"1 mBTC"`,
+//! `"100 sats"` or `"10.5 AUD"` — the format exchange APIs commonly use for amount fields.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::types::currency::{Currency, CurrencyError};
+
+/// A recognized sub-unit denomination of a base currency, e.g. `mBTC` is `10^-3` of a `BTC`.
+struct DenominationRecord {
+    base_code: &'static str,
+    /// Number of decimal places smaller than the base currency, e.g. `3` for `mBTC`, `8` for
+    /// `sat`. A value's magnitude in the base currency is `raw_value * 10^-sub_unit_decimals`.
+    sub_unit_decimals: u32,
+}
+
+/// Known crypto sub-unit denominations, keyed by their normalized (uppercased, `µ`/`μ` → `U`)
+/// ticker.
+static DENOMINATIONS: phf::Map<&'static str, DenominationRecord> = phf::phf_map! {
+    "MBTC" => DenominationRecord { base_code: "BTC", sub_unit_decimals: 3 },
+    "UBTC" => DenominationRecord { base_code: "BTC", sub_unit_decimals: 6 },
+    "SAT" => DenominationRecord { base_code: "BTC", sub_unit_decimals: 8 },
+    "SATS" => DenominationRecord { base_code: "BTC", sub_unit_decimals: 8 },
+};
+
+/// Errors returned when parsing a denominated amount string.
+#[derive(Error, Eq, PartialEq, Clone, Debug)]
+pub enum DenominationError {
+    #[error("expected \"<amount> <unit>\", got: {0:?}")]
+    InvalidFormat(String),
+    #[error("invalid amount {0:?}: {1}")]
+    InvalidNumber(String, String),
+    #[error("unknown denomination: {0:?}")]
+    UnknownDenomination(String),
+    #[error(transparent)]
+    UnknownCurrency(#[from] CurrencyError),
+}
+
+/// Normalizes a unit for lookup: uppercases it and folds the micro sign (`µ`/`μ`) to `U`, so
+/// `"µBTC"`, `"uBTC"` and `"UBTC"` all resolve to the same denomination.
+fn normalize_unit(unit: &str) -> String {
+    unit.chars()
+        .map(|c| match c {
+            'µ' | 'μ' => 'u',
+            other => other,
+        })
+        .collect::<String>()
+        .to_ascii_uppercase()
+}
+
+/// Parses a denominated amount string like `"1 BTC"`, `"1 mBTC"`, `"100 sats"` or `"10.5 AUD"`
+/// into the value and [`Currency`] it represents, scaling sub-unit crypto denominations (`mBTC`,
+/// `µBTC`, `sat`) up to the value of their base currency.
+pub fn parse_denominated_str(input: &str) -> Result<(Decimal, Currency), DenominationError> {
+    let trimmed = input.trim();
+    let (raw_amount, unit) = trimmed
+        .split_once(char::is_whitespace)
+        .map(|(amount, unit)| (amount.trim(), unit.trim()))
+        .filter(|(amount, unit)| !amount.is_empty() && !unit.is_empty())
+        .ok_or_else(|| DenominationError::InvalidFormat(input.to_string()))?;
+
+    let raw_value = Decimal::from_str(raw_amount)
+        .map_err(|e| DenominationError::InvalidNumber(raw_amount.to_string(), e.to_string()))?;
+
+    let normalized_unit = normalize_unit(unit);
+    if let Some(denomination) = DENOMINATIONS.get(normalized_unit.as_str()) {
+        let currency = Currency::from_str(denomination.base_code)?;
+        let value = raw_value * Decimal::new(1, denomination.sub_unit_decimals);
+        return Ok((value, currency));
+    }
+
+    match Currency::from_str(unit) {
+        Ok(currency) => Ok((raw_value, currency)),
+        Err(_) => Err(DenominationError::UnknownDenomination(unit.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_base_crypto_unit() {
+        let (value, currency) = parse_denominated_str("1 BTC").unwrap();
+
+        assert_eq!(value, dec!(1));
+        assert_eq!(currency.code.as_str(), "BTC");
+    }
+
+    #[test]
+    fn test_parse_milli_btc() {
+        let (value, currency) = parse_denominated_str("1 mBTC").unwrap();
+
+        assert_eq!(value, dec!(0.001));
+        assert_eq!(currency.code.as_str(), "BTC");
+    }
+
+    #[test]
+    fn test_parse_micro_btc_with_micro_sign() {
+        let (value, currency) = parse_denominated_str("1 µBTC").unwrap();
+
+        assert_eq!(value, dec!(0.000001));
+        assert_eq!(currency.code.as_str(), "BTC");
+    }
+
+    #[test]
+    fn test_parse_sats() {
+        let (value, currency) = parse_denominated_str("100 sats").unwrap();
+
+        assert_eq!(value, dec!(0.000001));
+        assert_eq!(currency.code.as_str(), "BTC");
+    }
+
+    #[test]
+    fn test_parse_bare_fiat_code() {
+        let (value, currency) = parse_denominated_str("10.5 AUD").unwrap();
+
+        assert_eq!(value, dec!(10.5));
+        assert_eq!(currency.code.as_str(), "AUD");
+    }
+
+    #[test]
+    fn test_parse_invalid_format_missing_unit() {
+        let result = parse_denominated_str("10.5");
+
+        assert_eq!(
+            result,
+            Err(DenominationError::InvalidFormat("10.5".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_number() {
+        let result = parse_denominated_str("abc BTC");
+
+        assert!(matches!(result, Err(DenominationError::InvalidNumber(..))));
+    }
+
+    #[test]
+    fn test_parse_unknown_denomination() {
+        let result = parse_denominated_str("1 XYZ");
+
+        assert_eq!(
+            result,
+            Err(DenominationError::UnknownDenomination("XYZ".to_string()))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::types::currency::Currency;
+
+/// Errors returned when an [`Exchange`] cannot resolve or apply a conversion.
+#[derive(Error, Eq, PartialEq, Clone, Debug)]
+pub enum ExchangeError {
+    #[error("no conversion path from {from} to {to}")]
+    NoRatePath { from: String, to: String },
+    #[error("rate must be positive, was {rate}")]
+    NonPositiveRate { rate: Decimal },
+}
+
+/// A directed conversion rate between two currencies, e.g. 1 `from` == `rate` `to`.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ExchangeRate {
+    pub from: Currency,
+    pub to: Currency,
+    pub rate: Decimal,
+}
+
+/// A registry of directed [`ExchangeRate`] quotes, supporting indirect (triangulated) conversion
+/// when no direct quote exists between two currencies.
+///
+/// Quotes are stored keyed by the `(from, to)` pair of currency codes, rather than a concatenated
+/// string — currency codes aren't fixed-length (3-character ISO codes alongside 4+ character
+/// crypto tickers like `USDT`), so concatenation can collide across different pairs. When a direct
+/// quote is absent, [`Exchange::get_rate`] walks the graph of known quotes breadth-first — each
+/// stored quote also implies its inverse `1 / rate` edge — multiplying rates along the shortest
+/// discovered path. Composite rates found this way are cached so repeated lookups for the same
+/// pair don't re-walk the graph.
+#[derive(Default, Debug)]
+pub struct Exchange {
+    rates: HashMap<(String, String), ExchangeRate>,
+    rate_cache: RefCell<HashMap<(String, String), Decimal>>,
+}
+
+impl Exchange {
+    pub fn new() -> Exchange {
+        Exchange::default()
+    }
+
+    fn pair_key(from: &str, to: &str) -> (String, String) {
+        (from.to_string(), to.to_string())
+    }
+
+    /// Inserts a directed rate, replacing any existing quote for the same `from`/`to` pair.
+    ///
+    /// This invalidates the composite rate cache, since adding a quote can open up new
+    /// conversion paths.
+    ///
+    /// Returns [`ExchangeError::NonPositiveRate`] if `rate` is zero or negative — a non-positive
+    /// rate has no valid inverse, and [`Exchange::neighbours`] divides by every stored rate to
+    /// derive its implied reverse edge.
+    pub fn add_or_update_rate(
+        &mut self,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> Result<(), ExchangeError> {
+        if rate <= Decimal::ZERO {
+            return Err(ExchangeError::NonPositiveRate { rate });
+        }
+        let key = Self::pair_key(&from.code, &to.code);
+        self.rates.insert(key, ExchangeRate { from, to, rate });
+        self.rate_cache.borrow_mut().clear();
+        Ok(())
+    }
+
+    /// Returns the directed rate from `from` to `to`, found directly, via a cached composite
+    /// rate, or by triangulating through the graph of known rates.
+    pub fn get_rate(&self, from: &Currency, to: &Currency) -> Result<Decimal, ExchangeError> {
+        if from.code == to.code {
+            return Ok(Decimal::ONE);
+        }
+
+        let key = Self::pair_key(&from.code, &to.code);
+        if let Some(direct) = self.rates.get(&key) {
+            return Ok(direct.rate);
+        }
+        if let Some(cached) = self.rate_cache.borrow().get(&key) {
+            return Ok(*cached);
+        }
+
+        let composite = self.find_composite_rate(&from.code, &to.code).ok_or_else(|| {
+            ExchangeError::NoRatePath {
+                from: from.code.to_string(),
+                to: to.code.to_string(),
+            }
+        })?;
+        self.rate_cache.borrow_mut().insert(key, composite);
+        Ok(composite)
+    }
+
+    /// Converts `amount` from `from` to `to`, quantizing the result to `to`'s precision.
+    pub fn convert(
+        &self,
+        amount: Decimal,
+        from: &Currency,
+        to: &Currency,
+    ) -> Result<Decimal, ExchangeError> {
+        let rate = self.get_rate(from, to)?;
+        Ok((amount * rate).round_dp(u32::from(to.precision)))
+    }
+
+    /// Returns the codes directly reachable from `code`, together with the rate to reach them —
+    /// one edge per stored quote that touches `code`: the quote itself when it starts at `code`,
+    /// or its implied `1 / rate` inverse when it ends there.
+    fn neighbours(&self, code: &str) -> Vec<(String, Decimal)> {
+        let mut out = Vec::new();
+        for quote in self.rates.values() {
+            if quote.from.code.as_str() == code {
+                out.push((quote.to.code.to_string(), quote.rate));
+            } else if quote.to.code.as_str() == code {
+                out.push((quote.from.code.to_string(), Decimal::ONE / quote.rate));
+            }
+        }
+        out
+    }
+
+    /// Breadth-first searches the graph of known rates for a path from `from` to `to`, returning
+    /// the product of the rates along the shortest path found.
+    fn find_composite_rate(&self, from: &str, to: &str) -> Option<Decimal> {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(from.to_string());
+        queue.push_back((from.to_string(), Decimal::ONE));
+
+        while let Some((code, acc_rate)) = queue.pop_front() {
+            for (neighbour, edge_rate) in self.neighbours(&code) {
+                if neighbour == to {
+                    return Some(acc_rate * edge_rate);
+                }
+                if visited.insert(neighbour.clone()) {
+                    queue.push_back((neighbour, acc_rate * edge_rate));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::enums::CurrencyType;
+    use crate::types::currency::Separators;
+    use crate::types::test_util::{aud, eur, gbp, usd};
+
+    /// A minimal currency with the given code, for codes not present in the built-in registry.
+    fn currency(code: &str) -> Currency {
+        Currency::new(
+            code,
+            2,
+            0,
+            code,
+            CurrencyType::FIAT,
+            code,
+            false,
+            Separators {
+                thousands: ',',
+                decimal: '.',
+            },
+        )
+    }
+
+    #[test]
+    fn test_pair_key_does_not_collide_across_different_code_lengths() {
+        // Naive string concatenation would make "USD" + "TWD" collide with "USDT" + "WD".
+        let mut exchange = Exchange::new();
+        exchange
+            .add_or_update_rate(currency("USD"), currency("TWD"), dec!(31.0))
+            .unwrap();
+        exchange
+            .add_or_update_rate(currency("USDT"), currency("WD"), dec!(99.0))
+            .unwrap();
+
+        assert_eq!(
+            exchange
+                .get_rate(&currency("USD"), &currency("TWD"))
+                .unwrap(),
+            dec!(31.0)
+        );
+        assert_eq!(
+            exchange
+                .get_rate(&currency("USDT"), &currency("WD"))
+                .unwrap(),
+            dec!(99.0)
+        );
+    }
+
+    #[test]
+    fn test_get_rate_same_currency() {
+        let exchange = Exchange::new();
+
+        assert_eq!(exchange.get_rate(&usd(), &usd()).unwrap(), Decimal::ONE);
+    }
+
+    #[test]
+    fn test_get_rate_direct() {
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(eur(), usd(), dec!(1.1)).unwrap();
+
+        assert_eq!(exchange.get_rate(&eur(), &usd()).unwrap(), dec!(1.1));
+    }
+
+    #[test]
+    fn test_get_rate_implied_inverse() {
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(eur(), usd(), dec!(1.1)).unwrap();
+
+        assert_eq!(
+            exchange.get_rate(&usd(), &eur()).unwrap(),
+            Decimal::ONE / dec!(1.1)
+        );
+    }
+
+    #[test]
+    fn test_get_rate_triangulated_path() {
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(eur(), usd(), dec!(1.1)).unwrap();
+        exchange.add_or_update_rate(gbp(), usd(), dec!(1.25)).unwrap();
+
+        // EUR -> USD -> GBP
+        let expected = dec!(1.1) * (Decimal::ONE / dec!(1.25));
+        assert_eq!(exchange.get_rate(&eur(), &gbp()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_add_or_update_rate_rejects_zero_rate() {
+        let mut exchange = Exchange::new();
+
+        let result = exchange.add_or_update_rate(eur(), usd(), dec!(0));
+
+        assert_eq!(
+            result,
+            Err(ExchangeError::NonPositiveRate { rate: dec!(0) })
+        );
+    }
+
+    #[test]
+    fn test_add_or_update_rate_rejects_negative_rate() {
+        let mut exchange = Exchange::new();
+
+        let result = exchange.add_or_update_rate(eur(), usd(), dec!(-1.1));
+
+        assert_eq!(
+            result,
+            Err(ExchangeError::NonPositiveRate { rate: dec!(-1.1) })
+        );
+    }
+
+    #[test]
+    fn test_get_rate_no_path() {
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(eur(), usd(), dec!(1.1)).unwrap();
+
+        let result = exchange.get_rate(&eur(), &aud());
+
+        assert_eq!(
+            result,
+            Err(ExchangeError::NoRatePath {
+                from: "EUR".to_string(),
+                to: "AUD".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_quantizes_to_target_precision() {
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(eur(), usd(), dec!(1.105)).unwrap();
+
+        let converted = exchange.convert(dec!(100), &eur(), &usd()).unwrap();
+
+        assert_eq!(converted, dec!(110.50));
+    }
+}
@@ -0,0 +1,242 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::types::currency::Currency;
+use crate::types::denomination::{parse_denominated_str, DenominationError};
+
+/// Errors returned when an arithmetic operation is attempted between two [`Money`] values in
+/// different currencies.
+#[derive(Error, Eq, PartialEq, Clone, Debug)]
+pub enum MoneyError {
+    #[error("currency mismatch: {lhs} != {rhs}")]
+    CurrencyMismatch { lhs: String, rhs: String },
+}
+
+/// A monetary amount paired with its [`Currency`], so the trading engine never has to reason
+/// about a bare `Decimal` that might silently mix currencies.
+///
+/// `value` is always quantized to the currency's [`precision`](Currency::precision). `+` and `-`
+/// verify both operands share the same currency and return a [`Result`], while scalar
+/// multiplication (e.g. applying a fee rate) is always allowed since it can't mix currencies.
+#[repr(C)]
+#[derive(Eq, PartialEq, Clone, Hash, Debug)]
+pub struct Money {
+    pub value: Decimal,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(value: Decimal, currency: Currency) -> Money {
+        let precision = u32::from(currency.precision);
+        Money {
+            value: value.round_dp(precision),
+            currency,
+        }
+    }
+
+    /// Adds `rhs` to `self`, returning `None` if the currencies differ or the sum overflows,
+    /// rather than panicking. Intended for infallible hot paths where a currency mismatch should
+    /// be treated the same as any other "can't produce a result" case.
+    pub fn checked_add(&self, rhs: &Money) -> Option<Money> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.value
+            .checked_add(rhs.value)
+            .map(|value| Money::new(value, self.currency.clone()))
+    }
+
+    /// Parses a denominated amount string like `"1 BTC"`, `"1 mBTC"`, `"100 sats"` or
+    /// `"10.5 AUD"` into a [`Money`], scaling sub-unit crypto denominations up to their base
+    /// currency's value. Useful when ingesting exchange API fields that embed the unit in the
+    /// value string.
+    pub fn from_denominated_str(input: &str) -> Result<Money, DenominationError> {
+        let (value, currency) = parse_denominated_str(input)?;
+        Ok(Money::new(value, currency))
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` if the currencies differ or the difference
+    /// underflows, rather than panicking.
+    pub fn checked_sub(&self, rhs: &Money) -> Option<Money> {
+        if self.currency != rhs.currency {
+            return None;
+        }
+        self.value
+            .checked_sub(rhs.value)
+            .map(|value| Money::new(value, self.currency.clone()))
+    }
+}
+
+impl Add for Money {
+    type Output = Result<Money, MoneyError>;
+
+    fn add(self, rhs: Money) -> Self::Output {
+        if self.currency != rhs.currency {
+            return Err(MoneyError::CurrencyMismatch {
+                lhs: self.currency.code.to_string(),
+                rhs: rhs.currency.code.to_string(),
+            });
+        }
+        Ok(Money::new(self.value + rhs.value, self.currency))
+    }
+}
+
+impl Sub for Money {
+    type Output = Result<Money, MoneyError>;
+
+    fn sub(self, rhs: Money) -> Self::Output {
+        if self.currency != rhs.currency {
+            return Err(MoneyError::CurrencyMismatch {
+                lhs: self.currency.code.to_string(),
+                rhs: rhs.currency.code.to_string(),
+            });
+        }
+        Ok(Money::new(self.value - rhs.value, self.currency))
+    }
+}
+
+impl Mul<Decimal> for Money {
+    type Output = Money;
+
+    /// Scales `self` by a plain number, e.g. applying a commission rate. Always allowed, since a
+    /// scalar can never belong to the wrong currency.
+    fn mul(self, rhs: Decimal) -> Money {
+        Money::new(self.value * rhs, self.currency)
+    }
+}
+
+impl PartialOrd for Money {
+    /// Returns `None` if `self` and `other` are in different currencies, since amounts in
+    /// different currencies are not comparable without a conversion rate.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency != other.currency {
+            return None;
+        }
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.currency.format_amount(self.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::types::test_util::{eur, usd};
+
+    #[test]
+    fn test_new_quantizes_to_currency_precision() {
+        let money = Money::new(dec!(10.12345), usd());
+
+        assert_eq!(money.value, dec!(10.12));
+    }
+
+    #[test]
+    fn test_add_same_currency() {
+        let result = Money::new(dec!(10), usd()) + Money::new(dec!(5), usd());
+
+        assert_eq!(result, Ok(Money::new(dec!(15), usd())));
+    }
+
+    #[test]
+    fn test_add_different_currency_returns_err() {
+        let result = Money::new(dec!(10), usd()) + Money::new(dec!(5), eur());
+
+        assert_eq!(
+            result,
+            Err(MoneyError::CurrencyMismatch {
+                lhs: "USD".to_string(),
+                rhs: "EUR".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_sub_same_currency() {
+        let result = Money::new(dec!(10), usd()) - Money::new(dec!(4), usd());
+
+        assert_eq!(result, Ok(Money::new(dec!(6), usd())));
+    }
+
+    #[test]
+    fn test_mul_scalar_always_allowed() {
+        let result = Money::new(dec!(10), usd()) * dec!(1.1);
+
+        assert_eq!(result, Money::new(dec!(11), usd()));
+    }
+
+    #[test]
+    fn test_partial_ord_same_currency() {
+        assert!(Money::new(dec!(10), usd()) > Money::new(dec!(5), usd()));
+    }
+
+    #[test]
+    fn test_partial_ord_different_currency_is_none() {
+        let lhs = Money::new(dec!(10), usd());
+        let rhs = Money::new(dec!(5), eur());
+
+        assert_eq!(lhs.partial_cmp(&rhs), None);
+    }
+
+    #[test]
+    fn test_checked_add_overflow_returns_none() {
+        let max = Money::new(Decimal::MAX, usd());
+
+        assert_eq!(max.checked_add(&max), None);
+    }
+
+    #[test]
+    fn test_checked_add_currency_mismatch_returns_none() {
+        let usd_amount = Money::new(dec!(10), usd());
+        let eur_amount = Money::new(dec!(10), eur());
+
+        assert_eq!(usd_amount.checked_add(&eur_amount), None);
+    }
+
+    #[test]
+    fn test_display_uses_currency_formatting() {
+        let money = Money::new(dec!(1234.5), usd());
+
+        assert_eq!(money.to_string(), "$1,234.50");
+    }
+
+    #[test]
+    fn test_from_denominated_str_sub_unit() {
+        let money = Money::from_denominated_str("100 sats").unwrap();
+
+        assert_eq!(money.value, dec!(0.00000100));
+        assert_eq!(money.currency.code.as_str(), "BTC");
+    }
+
+    #[test]
+    fn test_from_denominated_str_fiat() {
+        let money = Money::from_denominated_str("10.5 AUD").unwrap();
+
+        assert_eq!(money.value, dec!(10.50));
+        assert_eq!(money.currency.code.as_str(), "AUD");
+    }
+}
@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! `serde` support for [`Currency`] and [`CurrencyType`], gated behind the `serde-support`
+//! feature. Deserializing a [`Currency`] accepts either a full struct or a bare alpha-3 code (or
+//! crypto ticker) string that resolves against the built-in registry, so config files and
+//! message payloads can reference currencies compactly.
+//!
+//! [`CurrencyType`] is defined in [`crate::enums`], so its `Serialize`/`Deserialize` impls are
+//! written by hand here rather than derived at its definition site.
+
+use std::str::FromStr;
+
+use serde::ser::SerializeStruct;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::enums::CurrencyType;
+use crate::types::currency::{Currency, Separators};
+
+impl Serialize for CurrencyType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = match self {
+            CurrencyType::FIAT => "FIAT",
+            CurrencyType::CRYPTO => "CRYPTO",
+        };
+        serializer.serialize_str(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match name.as_str() {
+            "FIAT" => Ok(CurrencyType::FIAT),
+            "CRYPTO" => Ok(CurrencyType::CRYPTO),
+            other => Err(de::Error::custom(format!("unknown currency type: {other}"))),
+        }
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Currency", 8)?;
+        state.serialize_field("code", self.code.as_str())?;
+        state.serialize_field("precision", &self.precision)?;
+        state.serialize_field("iso4217", &self.iso4217)?;
+        state.serialize_field("name", self.name.as_str())?;
+        state.serialize_field("currency_type", &self.currency_type)?;
+        state.serialize_field("symbol", self.symbol.as_str())?;
+        state.serialize_field("symbol_first", &self.symbol_first)?;
+        state.serialize_field("separators", &self.separators)?;
+        state.end()
+    }
+}
+
+/// The full-struct shape accepted when deserializing a [`Currency`].
+#[derive(Deserialize)]
+struct CurrencyData {
+    code: String,
+    precision: u8,
+    iso4217: u16,
+    name: String,
+    currency_type: CurrencyType,
+    symbol: String,
+    symbol_first: bool,
+    separators: Separators,
+}
+
+/// Either a bare currency code (resolved against the built-in registry) or a full struct.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CurrencyRepr {
+    Code(String),
+    Full(CurrencyData),
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match CurrencyRepr::deserialize(deserializer)? {
+            CurrencyRepr::Code(code) => Currency::from_str(&code).map_err(de::Error::custom),
+            CurrencyRepr::Full(data) => Ok(Currency::new(
+                &data.code,
+                data.precision,
+                data.iso4217,
+                &data.name,
+                data.currency_type,
+                &data.symbol,
+                data.symbol_first,
+                data.separators,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_util::aud;
+
+    #[test]
+    fn test_serialize_then_deserialize_round_trips() {
+        let currency = aud();
+
+        let json = serde_json::to_string(&currency).unwrap();
+        let deserialized: Currency = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, currency);
+    }
+
+    #[test]
+    fn test_deserialize_from_bare_code() {
+        let deserialized: Currency = serde_json::from_str("\"aud\"").unwrap();
+
+        assert_eq!(deserialized, aud());
+    }
+
+    #[test]
+    fn test_deserialize_unknown_bare_code_errors() {
+        let result: Result<Currency, _> = serde_json::from_str("\"XXX\"");
+
+        assert!(result.is_err());
+    }
+}
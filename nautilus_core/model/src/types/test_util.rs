@@ -0,0 +1,39 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Shared `Currency` fixtures for `types` module test code, so each test module doesn't
+//! re-derive the same handful of `Currency::from_str(..).unwrap()` calls.
+
+#![cfg(test)]
+
+use std::str::FromStr;
+
+use crate::types::currency::Currency;
+
+pub(crate) fn usd() -> Currency {
+    Currency::from_str("USD").unwrap()
+}
+
+pub(crate) fn eur() -> Currency {
+    Currency::from_str("EUR").unwrap()
+}
+
+pub(crate) fn gbp() -> Currency {
+    Currency::from_str("GBP").unwrap()
+}
+
+pub(crate) fn aud() -> Currency {
+    Currency::from_str("AUD").unwrap()
+}